@@ -7,7 +7,7 @@ use {
     chrono::{DateTime, Utc},
     itertools::Itertools,
     number::serialization::HexOrDecimalU256,
-    primitive_types::{H160, U256},
+    primitive_types::{H160, H256, U256},
     serde::{Deserialize, Serialize},
     serde_with::{serde_as, DisplayFromStr},
     std::{
@@ -57,9 +57,22 @@ impl Request {
 }
 
 impl Response {
+    /// Converts every solution, pairing each with the access list its
+    /// solver proposed. The access list travels alongside the domain
+    /// `Solution` rather than through it, since it isn't part of the
+    /// competition (score, orders, prices) `Solution` represents: it's
+    /// consumed only by the submission path, which validates it via
+    /// `eth_createAccessList` before use (see
+    /// `driver::infra::mempool::access_list`).
+    #[allow(clippy::type_complexity)]
     pub fn into_domain(
         self,
-    ) -> Vec<Result<domain::competition::Solution, domain::competition::SolutionError>> {
+    ) -> Vec<
+        Result<
+            (domain::competition::Solution, Vec<AccessListEntry>),
+            domain::competition::SolutionError,
+        >,
+    > {
         self.solutions
             .into_iter()
             .map(Solution::into_domain)
@@ -104,29 +117,35 @@ pub struct TradedAmounts {
 impl Solution {
     pub fn into_domain(
         self,
-    ) -> Result<domain::competition::Solution, domain::competition::SolutionError> {
-        Ok(domain::competition::Solution::new(
-            self.solution_id,
-            self.submission_address.into(),
-            domain::competition::Score::new(self.score.into())?,
-            self.orders
-                .into_iter()
-                .map(|(o, amounts)| {
-                    (
-                        o.into(),
-                        domain::competition::TradedAmounts {
-                            sell: amounts.sell_amount.into(),
-                            buy: amounts.buy_amount.into(),
-                        },
-                    )
-                })
-                .collect(),
-            self.clearing_prices
-                .into_iter()
-                .map(|(token, price)| {
-                    domain::auction::Price::new(price.into()).map(|price| (token.into(), price))
-                })
-                .collect::<Result<_, _>>()?,
+    ) -> Result<(domain::competition::Solution, Vec<AccessListEntry>), domain::competition::SolutionError>
+    {
+        let access_list = self.access_list.unwrap_or_default();
+        Ok((
+            domain::competition::Solution::new(
+                self.solution_id,
+                self.submission_address.into(),
+                domain::competition::Score::new(self.score.into())?,
+                self.orders
+                    .into_iter()
+                    .map(|(o, amounts)| {
+                        (
+                            o.into(),
+                            domain::competition::TradedAmounts {
+                                sell: amounts.sell_amount.into(),
+                                buy: amounts.buy_amount.into(),
+                            },
+                        )
+                    })
+                    .collect(),
+                self.clearing_prices
+                    .into_iter()
+                    .map(|(token, price)| {
+                        domain::auction::Price::new(price.into())
+                            .map(|price| (token.into(), price))
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            access_list,
         ))
     }
 }
@@ -141,12 +160,32 @@ pub struct Solution {
     pub solution_id: u64,
     #[serde_as(as = "HexOrDecimalU256")]
     pub score: U256,
-    /// Address used by the driver to submit the settlement onchain.
+    /// Address used by the driver to submit the settlement onchain. This is
+    /// usually an EOA that signs a raw transaction directly, but it may also
+    /// be a smart-contract signer (e.g. a Gnosis Safe) configured via the
+    /// solver's `[[solver]] account`; in that case the driver collects the
+    /// required threshold of owner signatures off-chain and submits through
+    /// the contract's `execTransaction` entrypoint instead.
     pub submission_address: H160,
     pub orders: HashMap<boundary::OrderUid, TradedAmounts>,
     #[serde_as(as = "HashMap<_, HexOrDecimalU256>")]
     pub clearing_prices: HashMap<H160, U256>,
     pub gas: Option<u64>,
+    /// Accounts and storage slots touched by the settlement transaction, as
+    /// determined by the solver's own simulation. When present, the driver
+    /// submits the settlement as an access-list (or access-list + dynamic
+    /// fee) transaction pre-declaring these, making them "warm" on first
+    /// access and lowering settlement gas.
+    #[serde(default)]
+    pub access_list: Option<Vec<AccessListEntry>>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AccessListEntry {
+    pub address: H160,
+    pub storage_keys: Vec<H256>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize)]
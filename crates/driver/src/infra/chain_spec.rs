@@ -0,0 +1,140 @@
+//! Per-network defaults for contracts and parameters, keyed by chain ID,
+//! the same way a chain's genesis/params file works.
+//!
+//! Without this, every deployment's config file has to hand-write the
+//! settlement contract, wrapped-native token and known CoW-AMM
+//! factories/helpers, and the same addresses have to be kept in sync across
+//! every module that builds a [`ChainSpec`]. Adding support for a new
+//! network then becomes a data change here instead of editing config
+//! templates everywhere.
+
+use primitive_types::H160;
+
+/// A known CoW-AMM factory/helper pair, plus the block from which the
+/// autopilot should start indexing it.
+#[derive(Clone, Copy, Debug)]
+pub struct CowAmm {
+    pub factory: H160,
+    pub helper: H160,
+    pub index_start_block: u64,
+}
+
+/// Network-specific defaults resolved for a given chain ID.
+#[derive(Clone, Debug)]
+pub struct ChainSpec {
+    pub chain_id: u64,
+    pub settlement_contract: H160,
+    pub wrapped_native_token: H160,
+    pub cow_amms: Vec<CowAmm>,
+    pub base_tokens: Vec<H160>,
+    pub trusted_tokens: Vec<H160>,
+}
+
+/// Resolves the [`ChainSpec`] for a chain ID known to this binary.
+///
+/// The driver calls this at startup with the chain ID returned by
+/// `eth_chainId` (see [`crate::infra::config::contracts::resolve`]) and
+/// falls back to the inline `[contracts]` overrides in its config file when
+/// the network isn't in the registry (e.g. a local or test deployment with
+/// freshly deployed contracts).
+pub fn for_chain(chain_id: u64) -> Option<ChainSpec> {
+    registry().into_iter().find(|spec| spec.chain_id == chain_id)
+}
+
+/// The built-in registry of supported networks.
+///
+/// `GPv2Settlement` is deployed at the same address on every network via
+/// `CREATE2`. CoW-AMM factory/helper addresses and their index-start blocks
+/// are sourced from the contracts package's deployment receipts, which
+/// aren't part of this checkout, so `cow_amms` is left empty per network
+/// rather than guessed; everything else comes from each network's public
+/// token list.
+fn registry() -> Vec<ChainSpec> {
+    let gp_v2_settlement = h160("9008D19f58AAbD9eD0D60971565AA8510560ab41");
+
+    vec![
+        ChainSpec {
+            chain_id: 1, // mainnet
+            settlement_contract: gp_v2_settlement,
+            wrapped_native_token: h160("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"), // WETH
+            cow_amms: Vec::new(),
+            base_tokens: Vec::new(),
+            trusted_tokens: vec![
+                h160("A0b86991c6218b36c1D19D4a2e9Eb0cE3606eB48"), // USDC
+                h160("6B175474E89094C44Da98b954EedeAC495271d0F"), // DAI
+                h160("dAC17F958D2ee523a2206206994597C13D831ec7"), // USDT
+                h160("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599"), // WBTC
+            ],
+        },
+        ChainSpec {
+            chain_id: 100, // gnosis chain
+            settlement_contract: gp_v2_settlement,
+            wrapped_native_token: h160("e91D153E0b41518A2Ce8Dd3D7944Fa863463a97d"), // WXDAI
+            cow_amms: Vec::new(),
+            base_tokens: Vec::new(),
+            trusted_tokens: vec![
+                h160("DDAfbb505ad214D7b80b1f830fcCc89B60fb7A83"), // USDC (bridged)
+                h160("6A023CCd1ff6F2045C3309768eAd9E68F978f6e1"), // WETH (bridged)
+            ],
+        },
+        ChainSpec {
+            chain_id: 11_155_111, // sepolia
+            settlement_contract: gp_v2_settlement,
+            wrapped_native_token: h160("fFf9976782d46CC05630D1f6eBAb18b2324d6B14"), // WETH
+            cow_amms: Vec::new(),
+            base_tokens: Vec::new(),
+            trusted_tokens: Vec::new(),
+        },
+        ChainSpec {
+            chain_id: 8_453, // base
+            settlement_contract: gp_v2_settlement,
+            wrapped_native_token: h160("4200000000000000000000000000000000000006"), // WETH
+            cow_amms: Vec::new(),
+            base_tokens: Vec::new(),
+            trusted_tokens: vec![h160("833589fCD6eDb6E08f4c7C32D4f71b54bdA02913")], // USDC
+        },
+    ]
+}
+
+/// Parses a 20-byte hex address literal (no `0x` prefix).
+///
+/// Panics on malformed input with the offending literal rather than a bare
+/// slice-index panic, since every call site here is a registry constant
+/// checked once at startup.
+fn h160(hex: &str) -> H160 {
+    assert_eq!(
+        hex.len(),
+        40,
+        "address literal must be exactly 40 hex chars, got {} in {hex:?}",
+        hex.len(),
+    );
+    let mut bytes = [0u8; 20];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .unwrap_or_else(|_| panic!("invalid hex digit in address literal {hex:?}"));
+    }
+    H160(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_known_networks() {
+        for chain_id in [1, 100, 11_155_111, 8_453] {
+            assert!(for_chain(chain_id).is_some(), "missing spec for {chain_id}");
+        }
+    }
+
+    #[test]
+    fn unknown_chain_falls_back_to_none() {
+        assert!(for_chain(1_337).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be exactly 40 hex chars")]
+    fn h160_rejects_short_literal() {
+        h160("9008D19f58AAbD9eD0D60971565AA8510560ab0");
+    }
+}
@@ -0,0 +1,107 @@
+//! Resolves which contracts and parameters to use for the connected
+//! network, combining the [`chain_spec`] registry with any inline
+//! `[contracts]` overrides from the driver's config file.
+//!
+//! Called once at driver startup, right after querying `eth_chainId`:
+//! `resolve(eth_chain_id().await?, overrides)`.
+
+use {
+    crate::infra::chain_spec::{self, ChainSpec},
+    primitive_types::H160,
+};
+
+/// Inline `[contracts]` overrides from the config file. Every field is
+/// optional so a deployment only has to override what's actually different
+/// from the registry (e.g. a settlement contract freshly deployed on a
+/// local testnet), falling back to the registry entry field-by-field.
+/// `base_tokens` and `trusted_tokens` are added to the registry's lists
+/// rather than replacing them.
+#[derive(Clone, Debug, Default)]
+pub struct Overrides {
+    pub settlement_contract: Option<H160>,
+    pub wrapped_native_token: Option<H160>,
+    pub base_tokens: Vec<H160>,
+    pub trusted_tokens: Vec<H160>,
+}
+
+/// Resolves the [`ChainSpec`] to run with: registry values for `chain_id`,
+/// with any `overrides` applied on top.
+///
+/// Panics if neither the registry nor `overrides` provide a settlement
+/// contract or wrapped native token, since the driver can't run without
+/// them; this mirrors the config file's `deny_unknown_fields` structs,
+/// which already fail fast on malformed config at startup.
+pub fn resolve(chain_id: u64, overrides: Overrides) -> ChainSpec {
+    let spec = chain_spec::for_chain(chain_id);
+
+    let mut base_tokens = spec
+        .as_ref()
+        .map(|spec| spec.base_tokens.clone())
+        .unwrap_or_default();
+    base_tokens.extend(overrides.base_tokens);
+
+    let mut trusted_tokens = spec
+        .as_ref()
+        .map(|spec| spec.trusted_tokens.clone())
+        .unwrap_or_default();
+    trusted_tokens.extend(overrides.trusted_tokens);
+
+    ChainSpec {
+        chain_id,
+        settlement_contract: overrides
+            .settlement_contract
+            .or_else(|| spec.as_ref().map(|spec| spec.settlement_contract))
+            .expect("no chain-spec entry for this network and no inline `gp-v2-settlement` override"),
+        wrapped_native_token: overrides
+            .wrapped_native_token
+            .or_else(|| spec.as_ref().map(|spec| spec.wrapped_native_token))
+            .expect("no chain-spec entry for this network and no inline `weth` override"),
+        cow_amms: spec.map(|spec| spec.cow_amms).unwrap_or_default(),
+        base_tokens,
+        trusted_tokens,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_network_resolves_without_overrides() {
+        let spec = resolve(1, Overrides::default());
+        assert_eq!(spec.chain_id, 1);
+    }
+
+    #[test]
+    fn override_takes_precedence_over_registry() {
+        let custom = H160::repeat_byte(0x42);
+        let spec = resolve(
+            1,
+            Overrides {
+                settlement_contract: Some(custom),
+                ..Overrides::default()
+            },
+        );
+        assert_eq!(spec.settlement_contract, custom);
+    }
+
+    #[test]
+    #[should_panic(expected = "no chain-spec entry")]
+    fn unknown_network_requires_overrides() {
+        resolve(1_337, Overrides::default());
+    }
+
+    #[test]
+    fn unknown_network_with_full_overrides_resolves() {
+        let spec = resolve(
+            1_337,
+            Overrides {
+                settlement_contract: Some(H160::repeat_byte(1)),
+                wrapped_native_token: Some(H160::repeat_byte(2)),
+                base_tokens: Vec::new(),
+                trusted_tokens: Vec::new(),
+            },
+        );
+        assert_eq!(spec.chain_id, 1_337);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod pool_cache;
+pub mod uniswap_v2;
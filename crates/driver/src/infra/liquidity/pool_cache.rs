@@ -0,0 +1,130 @@
+//! A size-bounded, TTL-aware cache for liquidity pool state.
+//!
+//! Liquidity sources like UniswapV2 previously cached "missing pool" lookups
+//! (and would cache pool state) purely by TTL via `missing-pool-cache-time`,
+//! with no cap on the number of entries. Under adversarial token spam this
+//! lets both the pool cache and the negative cache grow without bound.
+//! [`BoundedPoolCache`] adds an LRU eviction policy on top of the existing
+//! TTL expiry so a source's memory usage is capped regardless of how many
+//! unique token pairs show up across auctions.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+struct Entry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
+/// An LRU cache bounded by `capacity`, with entries additionally expiring
+/// after `ttl`. Used for both the positive pool-state cache and the
+/// negative "missing pool" cache of a liquidity source, each with its own
+/// `max-pools` / `max-missing-pools` capacity.
+pub struct BoundedPoolCache<K, V> {
+    capacity: usize,
+    ttl: Duration,
+    entries: HashMap<K, Entry<V>>,
+    /// Keys in recency order, most-recently-used last (both `get` and
+    /// `insert` move a key to the end); the first key is the eviction
+    /// candidate. Kept by hand without an external LRU crate dependency.
+    order: Vec<K>,
+}
+
+impl<K: Clone + Eq + Hash, V> BoundedPoolCache<K, V> {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Returns the cached value for `key`, evicting it first if its TTL has
+    /// expired.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if self
+            .entries
+            .get(key)
+            .is_some_and(|entry| entry.inserted_at.elapsed() >= self.ttl)
+        {
+            self.remove(key);
+            return None;
+        }
+        self.touch(key);
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Inserts `value` for `key`, evicting the least-recently-used entry
+    /// first if the cache is already at capacity.
+    pub fn insert(&mut self, key: K, value: V) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            if let Some(lru) = self.order.first().cloned() {
+                self.remove(&lru);
+            }
+        }
+        self.entries.insert(
+            key.clone(),
+            Entry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+        self.touch(&key);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let key = self.order.remove(pos);
+            self.order.push(key);
+        } else {
+            self.order.push(key.clone());
+        }
+    }
+
+    fn remove(&mut self, key: &K) {
+        self.entries.remove(key);
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            self.order.remove(pos);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used_once_at_capacity() {
+        let mut cache = BoundedPoolCache::new(2, Duration::from_secs(60));
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        // touch 1 so 2 becomes the least-recently-used entry.
+        cache.get(&1);
+        cache.insert(3, "c");
+
+        assert!(cache.get(&1).is_some());
+        assert!(cache.get(&2).is_none());
+        assert!(cache.get(&3).is_some());
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn expires_entries_past_ttl() {
+        let mut cache = BoundedPoolCache::new(10, Duration::from_millis(0));
+        cache.insert(1, "a");
+        assert!(cache.get(&1).is_none());
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+//! `[[liquidity.uniswap-v2]]` config.
+
+use {
+    primitive_types::{H160, H256},
+    serde::Deserialize,
+    std::time::Duration,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub router: H160,
+    pub pool_code: H256,
+    #[serde(with = "humantime_serde")]
+    pub missing_pool_cache_time: Duration,
+    /// Maximum number of pools to keep in the positive cache before
+    /// evicting the least-recently-used entry.
+    #[serde(default = "default_max_pools")]
+    pub max_pools: usize,
+    /// Maximum number of entries to keep in the negative ("missing pool")
+    /// cache before evicting the least-recently-used entry.
+    #[serde(default = "default_max_missing_pools")]
+    pub max_missing_pools: usize,
+}
+
+fn default_max_pools() -> usize {
+    1_000
+}
+
+fn default_max_missing_pools() -> usize {
+    1_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_explicit_limits() {
+        let config: Config = toml::from_str(
+            r#"
+            router = "0x0000000000000000000000000000000000000001"
+            pool-code = "0x0000000000000000000000000000000000000000000000000000000000000002"
+            missing-pool-cache-time = "1h"
+            max-pools = 500
+            max-missing-pools = 2000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.max_pools, 500);
+        assert_eq!(config.max_missing_pools, 2_000);
+    }
+
+    #[test]
+    fn defaults_limits_when_absent() {
+        let config: Config = toml::from_str(
+            r#"
+            router = "0x0000000000000000000000000000000000000001"
+            pool-code = "0x0000000000000000000000000000000000000000000000000000000000000002"
+            missing-pool-cache-time = "1h"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.max_pools, 1_000);
+        assert_eq!(config.max_missing_pools, 1_000);
+    }
+}
@@ -0,0 +1,113 @@
+//! UniswapV2-style constant-product liquidity source.
+
+pub mod config;
+
+use {
+    super::pool_cache::BoundedPoolCache,
+    config::Config,
+    primitive_types::{H160, H256},
+};
+
+/// A UniswapV2 pool's reserves, keyed by its (sorted) token pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pool {
+    pub reserve0: u128,
+    pub reserve1: u128,
+}
+
+type Pair = (H160, H160);
+
+/// A UniswapV2 liquidity source.
+///
+/// Discovered pools and pairs known not to have one are each cached in a
+/// [`BoundedPoolCache`], bounded by the source's configured `max-pools` /
+/// `max-missing-pools` so neither cache can grow without bound under token
+/// spam, regardless of how many unique pairs show up across auctions.
+pub struct UniswapV2 {
+    router: H160,
+    pools: BoundedPoolCache<Pair, Pool>,
+    missing_pools: BoundedPoolCache<Pair, ()>,
+}
+
+impl UniswapV2 {
+    pub fn new(config: Config) -> Self {
+        Self {
+            router: config.router,
+            pools: BoundedPoolCache::new(config.max_pools, config.missing_pool_cache_time),
+            missing_pools: BoundedPoolCache::new(
+                config.max_missing_pools,
+                config.missing_pool_cache_time,
+            ),
+        }
+    }
+
+    pub fn router(&self) -> H160 {
+        self.router
+    }
+
+    /// Returns the cached pool for `pair`, if any is cached and not
+    /// expired. Does not distinguish "never looked up" from "known
+    /// missing"; use [`Self::is_known_missing`] for the latter.
+    pub fn cached_pool(&mut self, pair: Pair) -> Option<Pool> {
+        self.pools.get(&pair).copied()
+    }
+
+    pub fn is_known_missing(&mut self, pair: Pair) -> bool {
+        self.missing_pools.get(&pair).is_some()
+    }
+
+    pub fn cache_pool(&mut self, pair: Pair, pool: Pool) {
+        self.pools.insert(pair, pool);
+    }
+
+    pub fn cache_missing(&mut self, pair: Pair) {
+        self.missing_pools.insert(pair, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn config(max_pools: usize, max_missing_pools: usize) -> Config {
+        Config {
+            router: H160::zero(),
+            pool_code: H256::zero(),
+            missing_pool_cache_time: Duration::from_secs(3_600),
+            max_pools,
+            max_missing_pools,
+        }
+    }
+
+    #[test]
+    fn bounds_pool_cache_independently_of_missing_cache() {
+        let mut source = UniswapV2::new(config(1, 1));
+        let a = (H160::repeat_byte(1), H160::repeat_byte(2));
+        let b = (H160::repeat_byte(3), H160::repeat_byte(4));
+
+        source.cache_pool(
+            a,
+            Pool {
+                reserve0: 1,
+                reserve1: 1,
+            },
+        );
+        source.cache_missing(b);
+
+        assert!(source.cached_pool(a).is_some());
+        assert!(source.is_known_missing(b));
+
+        // Inserting a second pool evicts the first: the positive cache is
+        // bounded by its own `max-pools`, not the negative cache's size.
+        source.cache_pool(
+            b,
+            Pool {
+                reserve0: 2,
+                reserve1: 2,
+            },
+        );
+        assert!(source.cached_pool(a).is_none());
+        assert!(source.cached_pool(b).is_some());
+    }
+}
@@ -0,0 +1,127 @@
+//! Access-list handling for settlement submission (EIP-2930 / EIP-1559).
+//!
+//! A solver that has already simulated the settlement knows which accounts
+//! and storage slots it touches, so its proposed access list can be
+//! attached to the submitted transaction to make those "warm" on first
+//! access. But a stale or overly broad list can *raise* gas instead of
+//! lowering it (e.g. the sender is already warm; precompiles always are).
+//! [`validate`] recomputes the list via `eth_createAccessList` and keeps
+//! only entries both the solver and the node agree are worth declaring.
+
+use primitive_types::{H160, H256};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessListEntry {
+    pub address: H160,
+    pub storage_keys: Vec<H256>,
+}
+
+/// A minimal client capability for `eth_createAccessList`, kept as a trait
+/// so this logic is testable without a live node.
+#[async_trait::async_trait]
+pub trait AccessListOracle {
+    /// Returns the access list the node computes for the given call, which
+    /// may disagree with what the solver proposed.
+    async fn create_access_list(
+        &self,
+        to: H160,
+        data: &[u8],
+    ) -> Result<Vec<AccessListEntry>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// The highest precompile address on Ethereum mainnet; `0x01`-`0x09` are
+/// always warm and never worth declaring.
+const MAX_PRECOMPILE_ADDRESS: u64 = 0x09;
+
+/// Drops entries that would raise cost rather than lower it: the
+/// transaction sender (already warm) and precompiles.
+pub fn filter_beneficial(entries: Vec<AccessListEntry>, sender: H160) -> Vec<AccessListEntry> {
+    entries
+        .into_iter()
+        .filter(|entry| entry.address != sender && !is_precompile(entry.address))
+        .collect()
+}
+
+fn is_precompile(address: H160) -> bool {
+    let bytes = address.as_bytes();
+    bytes[..19].iter().all(|&byte| byte == 0)
+        && (1..=MAX_PRECOMPILE_ADDRESS).contains(&(bytes[19] as u64))
+}
+
+/// Recomputes the access list via `eth_createAccessList` and keeps only the
+/// entries the node also reports as touched, net of [`filter_beneficial`].
+pub async fn validate(
+    oracle: &impl AccessListOracle,
+    proposed: Vec<AccessListEntry>,
+    to: H160,
+    data: &[u8],
+    sender: H160,
+) -> Result<Vec<AccessListEntry>, Box<dyn std::error::Error + Send + Sync>> {
+    let onchain = oracle.create_access_list(to, data).await?;
+    let onchain_addresses: std::collections::HashSet<_> =
+        onchain.iter().map(|entry| entry.address).collect();
+    let agreed = proposed
+        .into_iter()
+        .filter(|entry| onchain_addresses.contains(&entry.address))
+        .collect();
+    Ok(filter_beneficial(agreed, sender))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(address: H160) -> AccessListEntry {
+        AccessListEntry {
+            address,
+            storage_keys: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn drops_sender_and_precompiles() {
+        let sender = H160::from_low_u64_be(0x5678);
+        let precompile = H160::from_low_u64_be(4);
+        let contract = H160::from_low_u64_be(0x1234);
+
+        let filtered = filter_beneficial(
+            vec![entry(sender), entry(precompile), entry(contract)],
+            sender,
+        );
+
+        assert_eq!(filtered, vec![entry(contract)]);
+    }
+
+    struct FixedOracle(Vec<AccessListEntry>);
+
+    #[async_trait::async_trait]
+    impl AccessListOracle for FixedOracle {
+        async fn create_access_list(
+            &self,
+            _to: H160,
+            _data: &[u8],
+        ) -> Result<Vec<AccessListEntry>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_only_entries_the_node_also_reports() {
+        let sender = H160::from_low_u64_be(0x5678);
+        let agreed = H160::from_low_u64_be(0x1002);
+        let solver_only = H160::from_low_u64_be(0x1003);
+
+        let oracle = FixedOracle(vec![entry(agreed)]);
+        let result = validate(
+            &oracle,
+            vec![entry(agreed), entry(solver_only)],
+            H160::zero(),
+            &[],
+            sender,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result, vec![entry(agreed)]);
+    }
+}
@@ -0,0 +1,45 @@
+//! `[[submission.mempool]]` config for a single mempool the driver submits
+//! settlements to.
+
+use {super::fee::GasPriceEncoding, serde::Deserialize};
+
+/// Which mempool a settlement is submitted to.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MempoolKind {
+    Public,
+}
+
+/// A single `[[submission.mempool]]` entry.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Mempool {
+    pub mempool: MempoolKind,
+    /// Transaction encoding to submit with through this mempool. Defaults
+    /// to `legacy` so existing config files keep working unchanged.
+    #[serde(default)]
+    pub gas_price_encoding: GasPriceEncoding,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_gas_price_encoding() {
+        let mempool: Mempool = toml::from_str(
+            r#"
+            mempool = "public"
+            gas-price-encoding = "eip1559"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(mempool.gas_price_encoding, GasPriceEncoding::Eip1559);
+    }
+
+    #[test]
+    fn defaults_to_legacy() {
+        let mempool: Mempool = toml::from_str(r#"mempool = "public""#).unwrap();
+        assert_eq!(mempool.gas_price_encoding, GasPriceEncoding::Legacy);
+    }
+}
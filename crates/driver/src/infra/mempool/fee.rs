@@ -0,0 +1,115 @@
+//! Gas pricing for settlement submission.
+//!
+//! The submission subsystem historically only ever built legacy (type 0x0)
+//! transactions with a single `gas_price`. This module adds EIP-1559 (type
+//! 0x02) support so a mempool can bid `max_fee_per_gas` /
+//! `max_priority_fee_per_gas` instead, letting the driver stay competitive
+//! during base fee spikes without overpaying once the base fee drops back
+//! down before the transaction is included.
+
+use {primitive_types::U256, serde::Deserialize};
+
+/// Transaction encoding used when submitting a settlement to a particular
+/// mempool. Configured per `[[submission.mempool]]` entry via
+/// `gas-price-encoding`, see [`crate::infra::mempool::config::Mempool`].
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GasPriceEncoding {
+    /// EIP-155 legacy transaction with a single `gas_price`.
+    #[default]
+    Legacy,
+    /// EIP-1559 (type 0x02) transaction.
+    Eip1559,
+}
+
+/// The gas price to use for a settlement transaction, bounded by the
+/// configured `gas-price-cap`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GasPrice {
+    Legacy {
+        gas_price: U256,
+    },
+    Eip1559 {
+        max_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+    },
+}
+
+impl GasPrice {
+    /// Computes the gas price to submit with, given the current block's
+    /// `base_fee_per_gas` and a desired `priority_fee_per_gas`.
+    ///
+    /// For EIP-1559, `max_fee_per_gas = base_fee_per_gas * multiplier +
+    /// priority_fee_per_gas`, which gives the transaction enough headroom to
+    /// stay valid across a few blocks while the base fee moves. The result
+    /// is always bounded by `gas_price_cap` so a fee spike can't make a
+    /// settlement unboundedly expensive.
+    pub fn new(
+        encoding: GasPriceEncoding,
+        base_fee_per_gas: U256,
+        priority_fee_per_gas: U256,
+        base_fee_multiplier: f64,
+        gas_price_cap: U256,
+    ) -> Self {
+        let max_fee_per_gas = scale(base_fee_per_gas, base_fee_multiplier)
+            .saturating_add(priority_fee_per_gas)
+            .min(gas_price_cap);
+        match encoding {
+            GasPriceEncoding::Legacy => Self::Legacy {
+                gas_price: max_fee_per_gas,
+            },
+            GasPriceEncoding::Eip1559 => Self::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas: priority_fee_per_gas.min(max_fee_per_gas),
+            },
+        }
+    }
+}
+
+/// Scales a `U256` by a floating point multiplier without losing precision
+/// on the typical multipliers used here (e.g. `1.125`, `2.0`).
+fn scale(value: U256, multiplier: f64) -> U256 {
+    const PRECISION: u64 = 10_000;
+    let numerator = (multiplier * PRECISION as f64).round() as u64;
+    value.saturating_mul(U256::from(numerator)) / U256::from(PRECISION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eip1559_applies_multiplier_and_priority_fee() {
+        let gas_price = GasPrice::new(
+            GasPriceEncoding::Eip1559,
+            U256::from(100),
+            U256::from(2),
+            1.5,
+            U256::from(1_000),
+        );
+        assert_eq!(
+            gas_price,
+            GasPrice::Eip1559 {
+                max_fee_per_gas: U256::from(152),
+                max_priority_fee_per_gas: U256::from(2),
+            }
+        );
+    }
+
+    #[test]
+    fn bounded_by_gas_price_cap() {
+        let gas_price = GasPrice::new(
+            GasPriceEncoding::Legacy,
+            U256::from(100),
+            U256::from(2),
+            2.0,
+            U256::from(150),
+        );
+        assert_eq!(
+            gas_price,
+            GasPrice::Legacy {
+                gas_price: U256::from(150),
+            }
+        );
+    }
+}
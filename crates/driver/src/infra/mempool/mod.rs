@@ -0,0 +1,4 @@
+pub mod access_list;
+pub mod config;
+pub mod fee;
+pub mod submit;
@@ -0,0 +1,139 @@
+//! Selects and prices the transaction type to submit a settlement with.
+//!
+//! This is the actual consumer of [`super::fee::GasPrice`]: it reads the
+//! connected chain's current base fee so an EIP-1559 mempool prices
+//! `max_fee_per_gas` off live conditions, instead of the gas price that was
+//! current at simulation time.
+
+use {
+    super::{
+        access_list::AccessListEntry,
+        fee::{GasPrice, GasPriceEncoding},
+    },
+    primitive_types::U256,
+};
+
+/// The subset of the latest block needed to price a settlement.
+pub struct BlockInfo {
+    pub base_fee_per_gas: U256,
+}
+
+/// Reads chain state needed to price a submission. A trait (rather than a
+/// concrete RPC client) so pricing is testable without a live node.
+#[async_trait::async_trait]
+pub trait Blocks {
+    async fn latest(&self) -> Result<BlockInfo, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// The transaction type to submit a settlement with, fully priced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Transaction {
+    /// Type 0x0.
+    Legacy { gas_price: U256 },
+    /// Type 0x1: legacy pricing, with an access list attached.
+    AccessList {
+        gas_price: U256,
+        access_list: Vec<AccessListEntry>,
+    },
+    /// Type 0x2, optionally with an access list attached.
+    Eip1559 {
+        max_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+        access_list: Vec<AccessListEntry>,
+    },
+}
+
+/// Prices a settlement submission against the connected chain's current
+/// base fee and picks the transaction type: legacy, access-list, or
+/// EIP-1559, depending on `encoding` and whether an access list is present.
+pub async fn price(
+    blocks: &impl Blocks,
+    encoding: GasPriceEncoding,
+    priority_fee_per_gas: U256,
+    base_fee_multiplier: f64,
+    gas_price_cap: U256,
+    access_list: Vec<AccessListEntry>,
+) -> Result<Transaction, Box<dyn std::error::Error + Send + Sync>> {
+    let block = blocks.latest().await?;
+    let gas_price = GasPrice::new(
+        encoding,
+        block.base_fee_per_gas,
+        priority_fee_per_gas,
+        base_fee_multiplier,
+        gas_price_cap,
+    );
+    Ok(match (gas_price, access_list.is_empty()) {
+        (GasPrice::Legacy { gas_price }, true) => Transaction::Legacy { gas_price },
+        (GasPrice::Legacy { gas_price }, false) => Transaction::AccessList {
+            gas_price,
+            access_list,
+        },
+        (
+            GasPrice::Eip1559 {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            },
+            _,
+        ) => Transaction::Eip1559 {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedBlock(U256);
+
+    #[async_trait::async_trait]
+    impl Blocks for FixedBlock {
+        async fn latest(&self) -> Result<BlockInfo, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(BlockInfo {
+                base_fee_per_gas: self.0,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn eip1559_reads_live_base_fee() {
+        let tx = price(
+            &FixedBlock(U256::from(100)),
+            GasPriceEncoding::Eip1559,
+            U256::from(2),
+            1.0,
+            U256::from(1_000),
+            Vec::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            tx,
+            Transaction::Eip1559 {
+                max_fee_per_gas: U256::from(102),
+                max_priority_fee_per_gas: U256::from(2),
+                access_list: Vec::new(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn legacy_with_access_list_becomes_type_0x1() {
+        let tx = price(
+            &FixedBlock(U256::from(100)),
+            GasPriceEncoding::Legacy,
+            U256::from(2),
+            1.0,
+            U256::from(1_000),
+            vec![AccessListEntry {
+                address: primitive_types::H160::zero(),
+                storage_keys: Vec::new(),
+            }],
+        )
+        .await
+        .unwrap();
+        assert!(matches!(tx, Transaction::AccessList { .. }));
+    }
+}
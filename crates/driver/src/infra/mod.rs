@@ -0,0 +1,5 @@
+pub mod chain_spec;
+pub mod config;
+pub mod liquidity;
+pub mod mempool;
+pub mod solver;
@@ -57,6 +57,8 @@ impl LiquidityProvider {
 router = "{:?}"
 pool-code = "{:?}"
 missing-pool-cache-time = "1h"
+max-pools = 1000
+max-missing-pools = 1000
 "#,
                 contracts.uniswap_v2_router.address(),
                 contracts.default_pool_code()
@@ -129,6 +131,9 @@ factory = "{:?}"
         .collect::<Vec<_>>()
         .join("\n");
 
+    // Contracts are deployed fresh for each test run, so this always uses
+    // inline overrides rather than the chain-spec registry, which only
+    // covers long-lived networks (mainnet/gnosis/sepolia/base).
     let config_file = config_tmp_file(format!(
         r#"
 [contracts]
@@ -148,6 +153,7 @@ gas-price-cap = "1000000000000"
 
 [[submission.mempool]]
 mempool = "public"
+gas-price-encoding = "eip1559"
 "#,
         contracts.gp_settlement.address(),
         contracts.weth.address(),